@@ -0,0 +1,106 @@
+use crate::{format::Format, Error, Message};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CborError {
+    /// Error during serialization.
+    #[error("ciborium serialization error")]
+    Ser(#[from] ciborium::ser::Error<std::io::Error>),
+
+    /// Error during deserialization.
+    #[error("ciborium deserialization error")]
+    De(#[from] ciborium::de::Error<std::io::Error>),
+
+    /// The message passed to [`Message::cbor`] is not a binary message, and thus can't be deserialized.
+    #[error("Can't deserialize CBOR from a message that is not binary.")]
+    NotBinaryMessage,
+}
+
+impl From<ciborium::ser::Error<std::io::Error>> for Error {
+    fn from(value: ciborium::ser::Error<std::io::Error>) -> Self {
+        CborError::from(value).into()
+    }
+}
+
+impl From<ciborium::de::Error<std::io::Error>> for Error {
+    fn from(value: ciborium::de::Error<std::io::Error>) -> Self {
+        CborError::from(value).into()
+    }
+}
+
+/// Marker type selecting the CBOR wire [`Format`].
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cbor;
+
+impl Format for Cbor {
+    fn to_message<T: Serialize + ?Sized>(value: &T) -> Result<Message, Error> {
+        Message::binary_from_cbor(value)
+    }
+
+    fn from_message<T: DeserializeOwned>(message: &Message) -> Result<T, Error> {
+        message.cbor()
+    }
+}
+
+impl Message {
+    /// Tries to serialize the value as CBOR, wrapped in a [`Message::Binary`].
+    ///
+    /// # Optional
+    ///
+    /// This requires the optional `cbor` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Serialization can fail if `T`'s implementation of `Serialize` decides to fail.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+    pub fn binary_from_cbor<T: Serialize + ?Sized>(value: &T) -> Result<Self, Error> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(value, &mut buf)?;
+        Ok(Message::Binary(buf))
+    }
+
+    /// Tries to deserialize the message body as CBOR.
+    ///
+    /// # Optional
+    ///
+    /// This requires that the optional `cbor` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the message is not binary, or it cannot be
+    /// properly deserialized to target type `T`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+    pub fn cbor<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        match self {
+            Self::Binary(x) => Ok(ciborium::from_reader(x.as_slice())?),
+            _ => Err(CborError::NotBinaryMessage.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        n: i32,
+    }
+
+    #[test]
+    fn cbor_round_trips_through_binary_message() {
+        let message = Message::binary_from_cbor(&Item { n: 42 }).unwrap();
+        assert!(matches!(message, Message::Binary(_)));
+        let item: Item = message.cbor().unwrap();
+        assert_eq!(item, Item { n: 42 });
+    }
+
+    #[test]
+    fn cbor_rejects_non_binary_message() {
+        let message = Message::Text("irrelevant".to_string());
+        let error = message.cbor::<Item>().unwrap_err();
+        assert!(error.to_string().contains("not binary"));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Error, Message};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A pluggable wire encoding usable with [`Message::from_serde`]/[`Message::to_serde`]
+/// and [`TypedWebSocket`](crate::typed::TypedWebSocket).
+///
+/// The built-in `json`, `cbor`, `msgpack` and `postcard` features each provide a marker
+/// type implementing this trait (see [`crate::json::Json`], [`crate::cbor::Cbor`],
+/// [`crate::msgpack::MsgPack`] and [`crate::postcard::Postcard`]). Implement it yourself
+/// to plug in any other serde-compatible codec.
+pub trait Format {
+    /// Serializes `value`, producing the [`Message`] variant appropriate for this format
+    /// (typically [`Message::Text`] for text-based formats, [`Message::Binary`] otherwise).
+    fn to_message<T: Serialize + ?Sized>(value: &T) -> Result<Message, Error>;
+
+    /// Deserializes a message previously produced by [`Format::to_message`].
+    fn from_message<T: DeserializeOwned>(message: &Message) -> Result<T, Error>;
+}
+
+impl Message {
+    /// Tries to serialize `value` using the wire format `F`.
+    ///
+    /// # Errors
+    ///
+    /// Fails whenever `F::to_message` fails, e.g. if `T`'s implementation of
+    /// `Serialize` decides to fail.
+    pub fn from_serde<F: Format, T: Serialize + ?Sized>(value: &T) -> Result<Self, Error> {
+        F::to_message(value)
+    }
+
+    /// Tries to deserialize this message using the wire format `F`.
+    ///
+    /// # Errors
+    ///
+    /// Fails whenever the message body isn't valid `F`, or it cannot be properly
+    /// deserialized to the target type `T`.
+    pub fn to_serde<F: Format, T: DeserializeOwned>(&self) -> Result<T, Error> {
+        F::from_message(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::Json;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        n: i32,
+    }
+
+    #[test]
+    fn from_serde_and_to_serde_round_trip_via_format() {
+        let message = Message::from_serde::<Json, _>(&Item { n: 7 }).unwrap();
+        assert!(matches!(message, Message::Text(_)));
+        let item: Item = message.to_serde::<Json, _>().unwrap();
+        assert_eq!(item, Item { n: 7 });
+    }
+}
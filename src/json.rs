@@ -1,15 +1,61 @@
-use crate::{Error, Message};
+use crate::{format::Format, Error, Message};
 use serde::{de::DeserializeOwned, Serialize};
 
+/// Marker type selecting the JSON wire [`Format`].
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+impl Format for Json {
+    fn to_message<T: Serialize + ?Sized>(value: &T) -> Result<Message, Error> {
+        Message::text_from_json(value)
+    }
+
+    fn from_message<T: DeserializeOwned>(message: &Message) -> Result<T, Error> {
+        message.json()
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum JsonError {
-    /// Error during serialization/deserialization.
+    /// Error during serialization.
     #[error("serde_json error")]
     SerdeJson(#[from] serde_json::Error),
 
+    /// Error while deserializing a [`Message::Text`] body. Carries the raw text that failed
+    /// to parse, so callers can log or inspect the exact frame that broke (the `source`
+    /// error's line/column point into `raw`). `JsonRpc::call` also returns this variant when
+    /// a response's `result` can't be deserialized; `raw` then holds only that `result` text.
+    #[error("serde_json error: {source}")]
+    DeserializeText {
+        source: serde_json::Error,
+        raw: String,
+    },
+
+    /// Error while deserializing a [`Message::Binary`] body. Carries the raw bytes that
+    /// failed to parse.
+    #[error("serde_json error: {source}")]
+    DeserializeBinary {
+        source: serde_json::Error,
+        raw: Vec<u8>,
+    },
+
     /// The message passed to [`Message::json`] is neither a text nor binary message, and thus can't be deserialized.
     #[error("Can't deserialize message that is neither text nor binary.")]
     NeitherTextNorBinaryMessage,
+
+    /// Error while deserializing one line of a [`Message::json_lines`] body. Carries the
+    /// zero-based index of the failing line and its raw text.
+    #[error("serde_json error on JSON Lines line {index}: {source}")]
+    JsonLines {
+        index: usize,
+        source: serde_json::Error,
+        raw: String,
+    },
+
+    /// The message passed to [`Message::json_lines`] is not a text message, and thus can't be deserialized.
+    #[error("Can't deserialize JSON Lines from a message that is not text.")]
+    NotTextMessage,
 }
 
 impl From<serde_json::Error> for Error {
@@ -62,16 +108,130 @@ impl Message {
     /// # Errors
     ///
     /// This method fails whenever the response body is not in `JSON` format,
-    /// or it cannot be properly deserialized to target type `T`.
+    /// or it cannot be properly deserialized to target type `T`. The returned
+    /// error carries the raw message body that failed to parse (see
+    /// [`JsonError::DeserializeText`]/[`JsonError::DeserializeBinary`]).
     ///
     /// For more details please see [`serde_json::from_str`] and
     /// [`serde_json::from_slice`].
     #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
     pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
         Ok(match self {
-            Self::Text(x) => serde_json::from_str(x)?,
-            Self::Binary(x) => serde_json::from_slice(x)?,
+            Self::Text(x) => serde_json::from_str(x).map_err(|source| JsonError::DeserializeText {
+                source,
+                raw: x.clone(),
+            })?,
+            Self::Binary(x) => {
+                serde_json::from_slice(x).map_err(|source| JsonError::DeserializeBinary {
+                    source,
+                    raw: x.clone(),
+                })?
+            }
             _ => return Err(JsonError::NeitherTextNorBinaryMessage.into()),
         })
     }
+
+    /// Serializes each item in `items` and joins them with `\n` into a single
+    /// [`Message::Text`] frame, i.e. [JSON Lines](https://jsonlines.org/) batched into one frame.
+    ///
+    /// # Optional
+    ///
+    /// This requires the optional `json` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Serialization can fail if any item's implementation of `Serialize` decides to fail.
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn text_from_json_lines<T: Serialize>(
+        items: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Error> {
+        let lines = items
+            .into_iter()
+            .map(|item| serde_json::to_string(&item))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Message::Text(lines.join("\n")))
+    }
+
+    /// Splits the message body on `\n` and deserializes each non-blank line as JSON;
+    /// lines that are empty or hold only whitespace (such as a lone `\r`) are skipped.
+    ///
+    /// # Optional
+    ///
+    /// This requires that the optional `json` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the message is not text, or any non-blank line can't be
+    /// deserialized to target type `T`; the error reports the failing line's index (see
+    /// [`JsonError::JsonLines`]).
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn json_lines<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        let Self::Text(body) = self else {
+            return Err(JsonError::NotTextMessage.into());
+        };
+        body.split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|source| {
+                    JsonError::JsonLines {
+                        index,
+                        source,
+                        raw: line.to_string(),
+                    }
+                    .into()
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_lines_round_trips_multiple_values() {
+        let message = Message::text_from_json_lines([1, 2, 3]).unwrap();
+        let values: Vec<i32> = message.json_lines().unwrap();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn json_lines_skips_crlf_blank_lines() {
+        let message = Message::Text("{\"a\":1}\r\n\r\n{\"a\":2}\r\n".to_string());
+        let values: Vec<serde_json::Value> = message.json_lines().unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0]["a"], 1);
+        assert_eq!(values[1]["a"], 2);
+    }
+
+    #[test]
+    fn json_lines_reports_failing_line_index() {
+        let message = Message::Text("{\"a\":1}\nnot json\n".to_string());
+        let error = message.json_lines::<serde_json::Value>().unwrap_err();
+        assert!(error.to_string().contains("line 1"));
+    }
+
+    #[test]
+    fn json_lines_rejects_non_text_message() {
+        let message = Message::Binary(b"{\"a\":1}".to_vec());
+        let error = message.json_lines::<serde_json::Value>().unwrap_err();
+        assert!(error.to_string().contains("not text"));
+    }
+
+    #[test]
+    fn json_deserialize_error_preserves_raw_text() {
+        let message = Message::Text("not json".to_string());
+        let error = message.json::<serde_json::Value>().unwrap_err();
+        assert!(format!("{error:?}").contains("not json"));
+    }
+
+    #[test]
+    fn json_deserialize_error_preserves_raw_binary() {
+        let raw = b"not json".to_vec();
+        let message = Message::Binary(raw.clone());
+        let error = message.json::<serde_json::Value>().unwrap_err();
+        assert!(format!("{error:?}").contains(&format!("{raw:?}")));
+    }
 }
@@ -0,0 +1,419 @@
+use crate::{json::JsonError, Error, Message, WebSocket};
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    sync::{mpsc, oneshot, Mutex},
+    task::JoinHandle,
+};
+
+/// A JSON-RPC 2.0 request/response id.
+///
+/// JSON-RPC allows ids to be either a number or a string; this crate always assigns
+/// numeric ids to outgoing calls but accepts either when matching a response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Id {
+    /// A numeric id, as assigned by [`JsonRpc::call`].
+    Num(u64),
+    /// A string id, accepted for compatibility with servers/peers that use them.
+    Str(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum JsonRpcError {
+    /// The server responded with a JSON-RPC error object.
+    #[error("JSON-RPC error {code}: {message}")]
+    Server {
+        code: i64,
+        message: String,
+        data: Option<Box<RawValue>>,
+    },
+
+    /// The underlying [`WebSocket`] closed, or its read half ended, before a response arrived.
+    #[error("the JSON-RPC connection was closed before a response arrived")]
+    Closed,
+}
+
+/// A server-initiated JSON-RPC notification (a frame with a `method` but no `id`).
+#[derive(Debug)]
+pub struct Notification {
+    pub method: String,
+    /// `params` is optional per the JSON-RPC 2.0 spec; `None` for notifications sent
+    /// without one (e.g. a bare heartbeat/ping).
+    pub params: Option<Box<RawValue>>,
+}
+
+#[derive(Serialize)]
+struct RequestEnvelope<'a, P> {
+    jsonrpc: &'static str,
+    id: &'a Id,
+    method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<P>,
+}
+
+#[derive(Serialize)]
+struct NotificationEnvelope<'a, P> {
+    jsonrpc: &'static str,
+    method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<P>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+    #[serde(default)]
+    data: Option<Box<RawValue>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseEnvelope {
+    #[allow(dead_code)]
+    jsonrpc: String,
+    #[serde(default)]
+    id: Option<Id>,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    result: Option<Box<RawValue>>,
+    #[serde(default)]
+    error: Option<ResponseError>,
+    #[serde(default)]
+    params: Option<Box<RawValue>>,
+}
+
+// A single frame may carry one response/notification object, or a JSON-RPC batch of them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Frame {
+    Batch(Vec<ResponseEnvelope>),
+    Single(ResponseEnvelope),
+}
+
+type PendingSender = oneshot::Sender<Result<Box<RawValue>, JsonRpcError>>;
+type PendingMap = Arc<Mutex<HashMap<Id, PendingSender>>>;
+
+/// A JSON-RPC 2.0 client layered over a [`WebSocket`].
+///
+/// [`JsonRpc::call`] assigns a monotonically increasing id to each request, serializes
+/// it via [`Message::text_from_json`], and awaits the matching response. A background
+/// task reads incoming frames, demultiplexing responses (routed back to the waiting
+/// [`JsonRpc::call`] by id) from server-initiated notifications (delivered through
+/// [`JsonRpc::next_notification`]).
+///
+/// # Optional
+///
+/// This requires that the optional `json-rpc` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "json-rpc")))]
+pub struct JsonRpc {
+    sink: SplitSink<WebSocket, Message>,
+    next_id: AtomicU64,
+    pending: PendingMap,
+    notifications: mpsc::UnboundedReceiver<Notification>,
+    demux: JoinHandle<()>,
+}
+
+impl JsonRpc {
+    /// Wraps `socket`, spawning a background task that demultiplexes incoming frames.
+    ///
+    /// The background task is aborted when the returned `JsonRpc` is dropped.
+    pub fn new(socket: WebSocket) -> Self {
+        let (sink, stream) = socket.split();
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let (notification_tx, notification_rx) = mpsc::unbounded_channel();
+        let demux = tokio::spawn(Self::demux(stream, pending.clone(), notification_tx));
+
+        Self {
+            sink,
+            next_id: AtomicU64::new(1),
+            pending,
+            notifications: notification_rx,
+            demux,
+        }
+    }
+
+    /// Parses one incoming frame body into the response/notification envelope(s) it carries,
+    /// handling the JSON-RPC batch form (a top-level array of envelopes). Returns `None` if
+    /// the body isn't a valid envelope or batch of envelopes.
+    fn parse_frame(body: &str) -> Option<Vec<ResponseEnvelope>> {
+        // `Box<RawValue>` fields can't be deserialized through an untagged enum (serde
+        // buffers the input first and loses the raw text), so pick the form up front.
+        let frame = if body.trim_start().starts_with('[') {
+            Frame::Batch(serde_json::from_str(body).ok()?)
+        } else {
+            Frame::Single(serde_json::from_str(body).ok()?)
+        };
+        Some(match frame {
+            Frame::Batch(envelopes) => envelopes,
+            Frame::Single(envelope) => vec![envelope],
+        })
+    }
+
+    /// Routes one parsed envelope to its matching pending call (by id) or, for
+    /// notifications (no id), to `notifications`.
+    fn dispatch_envelope(
+        envelope: ResponseEnvelope,
+        pending: &mut HashMap<Id, PendingSender>,
+        notifications: &mpsc::UnboundedSender<Notification>,
+    ) {
+        match envelope.id {
+            Some(id) => {
+                let result = if let Some(error) = envelope.error {
+                    Err(JsonRpcError::Server {
+                        code: error.code,
+                        message: error.message,
+                        data: error.data,
+                    })
+                } else if let Some(result) = envelope.result {
+                    Ok(result)
+                } else {
+                    return;
+                };
+                // An id with no matching sender is either unknown or already
+                // resolved (e.g. a duplicate); there's no caller left to notify.
+                if let Some(sender) = pending.remove(&id) {
+                    let _ = sender.send(result);
+                }
+            }
+            None => {
+                // `params` is optional per the spec; only `method` is required to
+                // recognize a frame as a notification.
+                if let Some(method) = envelope.method {
+                    let _ = notifications.send(Notification {
+                        method,
+                        params: envelope.params,
+                    });
+                }
+            }
+        }
+    }
+
+    async fn demux(
+        mut stream: SplitStream<WebSocket>,
+        pending: PendingMap,
+        notifications: mpsc::UnboundedSender<Notification>,
+    ) {
+        while let Some(message) = stream.next().await {
+            let Ok(message) = message else {
+                break;
+            };
+            let body = match &message {
+                Message::Text(text) => text.clone(),
+                Message::Binary(bytes) => match String::from_utf8(bytes.clone()) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                },
+                // Ping/Pong/Close frames carry no JSON-RPC envelope.
+                _ => continue,
+            };
+            let Some(envelopes) = Self::parse_frame(&body) else {
+                continue;
+            };
+            let mut pending = pending.lock().await;
+            for envelope in envelopes {
+                Self::dispatch_envelope(envelope, &mut pending, &notifications);
+            }
+        }
+
+        // The connection closed: wake every still-pending call rather than hanging forever.
+        for (_, sender) in pending.lock().await.drain() {
+            let _ = sender.send(Err(JsonRpcError::Closed));
+        }
+    }
+
+    /// Calls `method` with `params` and awaits the matching response.
+    ///
+    /// # Errors
+    ///
+    /// Fails if serialization/deserialization fails, if the server returns a JSON-RPC
+    /// error object, or if the connection closes before a response arrives.
+    pub async fn call<P: Serialize, R: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: P,
+    ) -> Result<R, Error> {
+        let id = Id::Num(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().await.insert(id.clone(), tx);
+
+        let envelope = RequestEnvelope {
+            jsonrpc: "2.0",
+            id: &id,
+            method,
+            params: Some(params),
+        };
+        let message = Message::text_from_json(&envelope)?;
+        if self.sink.send(message).await.is_err() {
+            self.pending.lock().await.remove(&id);
+            return Err(JsonRpcError::Closed.into());
+        }
+
+        match rx.await {
+            Ok(Ok(result)) => serde_json::from_str(result.get()).map_err(|source| {
+                JsonError::DeserializeText {
+                    source,
+                    raw: result.get().to_string(),
+                }
+                .into()
+            }),
+            Ok(Err(error)) => Err(error.into()),
+            Err(_) => Err(JsonRpcError::Closed.into()),
+        }
+    }
+
+    /// Sends `method` with `params` as a notification; no response is expected.
+    ///
+    /// # Errors
+    ///
+    /// Fails if serialization fails or the underlying socket rejects the send.
+    pub async fn notify<P: Serialize>(&mut self, method: &str, params: P) -> Result<(), Error> {
+        let envelope = NotificationEnvelope {
+            jsonrpc: "2.0",
+            method,
+            params: Some(params),
+        };
+        let message = Message::text_from_json(&envelope)?;
+        self.sink.send(message).await
+    }
+
+    /// Returns the next server-initiated notification, or `None` once the connection closes.
+    pub async fn next_notification(&mut self) -> Option<Notification> {
+        self.notifications.recv().await
+    }
+}
+
+impl Drop for JsonRpc {
+    fn drop(&mut self) {
+        // Otherwise the demux task (and the read half of the socket it owns) would keep
+        // running for as long as the peer keeps the connection open, well past the point
+        // this `JsonRpc` and its pending calls are gone.
+        self.demux.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_frame_reads_single_response() {
+        let envelopes = JsonRpc::parse_frame(r#"{"jsonrpc":"2.0","id":1,"result":42}"#).unwrap();
+        assert_eq!(envelopes.len(), 1);
+        assert_eq!(envelopes[0].id, Some(Id::Num(1)));
+    }
+
+    #[test]
+    fn parse_frame_reads_batched_responses() {
+        let envelopes = JsonRpc::parse_frame(
+            r#"[{"jsonrpc":"2.0","id":1,"result":1},{"jsonrpc":"2.0","id":2,"result":2}]"#,
+        )
+        .unwrap();
+        assert_eq!(envelopes.len(), 2);
+        assert_eq!(envelopes[0].id, Some(Id::Num(1)));
+        assert_eq!(envelopes[1].id, Some(Id::Num(2)));
+    }
+
+    #[test]
+    fn parse_frame_rejects_malformed_body() {
+        assert!(JsonRpc::parse_frame("not json").is_none());
+    }
+
+    #[test]
+    fn dispatch_routes_response_to_matching_pending_call() {
+        let mut pending = HashMap::new();
+        let (tx, mut rx) = oneshot::channel();
+        pending.insert(Id::Num(1), tx);
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        let envelope = JsonRpc::parse_frame(r#"{"jsonrpc":"2.0","id":1,"result":42}"#)
+            .unwrap()
+            .remove(0);
+        JsonRpc::dispatch_envelope(envelope, &mut pending, &notification_tx);
+
+        assert!(pending.is_empty());
+        assert!(rx.try_recv().unwrap().is_ok());
+        assert!(notification_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn dispatch_drops_responses_with_unknown_or_duplicate_id() {
+        let mut pending = HashMap::new();
+        let (notification_tx, _notification_rx) = mpsc::unbounded_channel();
+
+        // No sender was ever registered for id 7: this is indistinguishable from an id
+        // whose call already resolved (a duplicate response). Either way dispatch must
+        // not panic, and there's nothing left in `pending` to route it to.
+        let envelope = JsonRpc::parse_frame(r#"{"jsonrpc":"2.0","id":7,"result":42}"#)
+            .unwrap()
+            .remove(0);
+        JsonRpc::dispatch_envelope(envelope, &mut pending, &notification_tx);
+
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn dispatch_routes_error_response_to_pending_call() {
+        let mut pending = HashMap::new();
+        let (tx, mut rx) = oneshot::channel();
+        pending.insert(Id::Num(1), tx);
+        let (notification_tx, _notification_rx) = mpsc::unbounded_channel();
+
+        let envelope = JsonRpc::parse_frame(
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"not found"}}"#,
+        )
+        .unwrap()
+        .remove(0);
+        JsonRpc::dispatch_envelope(envelope, &mut pending, &notification_tx);
+
+        match rx.try_recv().unwrap() {
+            Err(JsonRpcError::Server { code, .. }) => assert_eq!(code, -32601),
+            other => panic!("expected a server error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn dispatch_routes_notification_without_id() {
+        let mut pending = HashMap::new();
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        let envelope =
+            JsonRpc::parse_frame(r#"{"jsonrpc":"2.0","method":"tick","params":{"n":1}}"#)
+                .unwrap()
+                .remove(0);
+        JsonRpc::dispatch_envelope(envelope, &mut pending, &notification_tx);
+
+        let notification = notification_rx.try_recv().expect("notification delivered");
+        assert_eq!(notification.method, "tick");
+        assert!(notification.params.is_some());
+    }
+
+    #[test]
+    fn dispatch_routes_notification_without_params() {
+        let mut pending = HashMap::new();
+        let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+
+        // `params` is optional per JSON-RPC 2.0 -- a bare heartbeat/ping notification like
+        // this one must still reach `next_notification`, not be dropped on the floor.
+        let envelope = JsonRpc::parse_frame(r#"{"jsonrpc":"2.0","method":"tick"}"#)
+            .unwrap()
+            .remove(0);
+        JsonRpc::dispatch_envelope(envelope, &mut pending, &notification_tx);
+
+        let notification = notification_rx.try_recv().expect("notification delivered");
+        assert_eq!(notification.method, "tick");
+        assert!(notification.params.is_none());
+    }
+}
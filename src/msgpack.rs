@@ -0,0 +1,106 @@
+use crate::{format::Format, Error, Message};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MsgPackError {
+    /// Error during serialization.
+    #[error("rmp-serde serialization error")]
+    Encode(#[from] rmp_serde::encode::Error),
+
+    /// Error during deserialization.
+    #[error("rmp-serde deserialization error")]
+    Decode(#[from] rmp_serde::decode::Error),
+
+    /// The message passed to [`Message::msgpack`] is not a binary message, and thus can't be deserialized.
+    #[error("Can't deserialize MessagePack from a message that is not binary.")]
+    NotBinaryMessage,
+}
+
+impl From<rmp_serde::encode::Error> for Error {
+    fn from(value: rmp_serde::encode::Error) -> Self {
+        MsgPackError::from(value).into()
+    }
+}
+
+impl From<rmp_serde::decode::Error> for Error {
+    fn from(value: rmp_serde::decode::Error) -> Self {
+        MsgPackError::from(value).into()
+    }
+}
+
+/// Marker type selecting the MessagePack wire [`Format`].
+#[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MsgPack;
+
+impl Format for MsgPack {
+    fn to_message<T: Serialize + ?Sized>(value: &T) -> Result<Message, Error> {
+        Message::binary_from_msgpack(value)
+    }
+
+    fn from_message<T: DeserializeOwned>(message: &Message) -> Result<T, Error> {
+        message.msgpack()
+    }
+}
+
+impl Message {
+    /// Tries to serialize the value as MessagePack, wrapped in a [`Message::Binary`].
+    ///
+    /// # Optional
+    ///
+    /// This requires the optional `msgpack` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Serialization can fail if `T`'s implementation of `Serialize` decides to fail.
+    #[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
+    pub fn binary_from_msgpack<T: Serialize + ?Sized>(value: &T) -> Result<Self, Error> {
+        rmp_serde::to_vec(value)
+            .map(Message::Binary)
+            .map_err(Into::into)
+    }
+
+    /// Tries to deserialize the message body as MessagePack.
+    ///
+    /// # Optional
+    ///
+    /// This requires that the optional `msgpack` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the message is not binary, or it cannot be
+    /// properly deserialized to target type `T`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
+    pub fn msgpack<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        match self {
+            Self::Binary(x) => rmp_serde::from_slice(x).map_err(Into::into),
+            _ => Err(MsgPackError::NotBinaryMessage.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        n: i32,
+    }
+
+    #[test]
+    fn msgpack_round_trips_through_binary_message() {
+        let message = Message::binary_from_msgpack(&Item { n: 42 }).unwrap();
+        assert!(matches!(message, Message::Binary(_)));
+        let item: Item = message.msgpack().unwrap();
+        assert_eq!(item, Item { n: 42 });
+    }
+
+    #[test]
+    fn msgpack_rejects_non_binary_message() {
+        let message = Message::Text("irrelevant".to_string());
+        let error = message.msgpack::<Item>().unwrap_err();
+        assert!(error.to_string().contains("not binary"));
+    }
+}
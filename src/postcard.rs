@@ -0,0 +1,96 @@
+use crate::{format::Format, Error, Message};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PostcardError {
+    /// Error during serialization/deserialization.
+    #[error("postcard error")]
+    Postcard(#[from] postcard::Error),
+
+    /// The message passed to [`Message::postcard`] is not a binary message, and thus can't be deserialized.
+    #[error("Can't deserialize postcard from a message that is not binary.")]
+    NotBinaryMessage,
+}
+
+impl From<postcard::Error> for Error {
+    fn from(value: postcard::Error) -> Self {
+        PostcardError::from(value).into()
+    }
+}
+
+/// Marker type selecting the [postcard](https://docs.rs/postcard) wire [`Format`].
+#[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Postcard;
+
+impl Format for Postcard {
+    fn to_message<T: Serialize + ?Sized>(value: &T) -> Result<Message, Error> {
+        Message::binary_from_postcard(value)
+    }
+
+    fn from_message<T: DeserializeOwned>(message: &Message) -> Result<T, Error> {
+        message.postcard()
+    }
+}
+
+impl Message {
+    /// Tries to serialize the value as postcard, wrapped in a [`Message::Binary`].
+    ///
+    /// # Optional
+    ///
+    /// This requires the optional `postcard` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// Serialization can fail if `T`'s implementation of `Serialize` decides to fail.
+    #[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+    pub fn binary_from_postcard<T: Serialize + ?Sized>(value: &T) -> Result<Self, Error> {
+        postcard::to_allocvec(value)
+            .map(Message::Binary)
+            .map_err(Into::into)
+    }
+
+    /// Tries to deserialize the message body as postcard.
+    ///
+    /// # Optional
+    ///
+    /// This requires that the optional `postcard` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the message is not binary, or it cannot be
+    /// properly deserialized to target type `T`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+    pub fn postcard<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        match self {
+            Self::Binary(x) => postcard::from_bytes(x).map_err(Into::into),
+            _ => Err(PostcardError::NotBinaryMessage.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        n: i32,
+    }
+
+    #[test]
+    fn postcard_round_trips_through_binary_message() {
+        let message = Message::binary_from_postcard(&Item { n: 42 }).unwrap();
+        assert!(matches!(message, Message::Binary(_)));
+        let item: Item = message.postcard().unwrap();
+        assert_eq!(item, Item { n: 42 });
+    }
+
+    #[test]
+    fn postcard_rejects_non_binary_message() {
+        let message = Message::Text("irrelevant".to_string());
+        let error = message.postcard::<Item>().unwrap_err();
+        assert!(error.to_string().contains("not binary"));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{format::Format, Error, Message, WebSocket};
+use futures_util::{Sink, Stream};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`WebSocket`] adapter that speaks `T` instead of raw [`Message`]s.
+///
+/// Wraps a socket `S` (defaulting to [`WebSocket`]) and encodes/decodes items using the
+/// wire [`Format`] `F` (defaulting to [`crate::json::Json`] when the `json` feature is
+/// enabled), implementing [`Sink<T>`] and [`Stream<Item = Result<T, Error>>`] so callers
+/// don't have to hand-roll `Message::to_serde`/`Message::from_serde` at every call site.
+/// Incoming control frames (ping/pong/close) are silently skipped by the stream rather
+/// than surfaced as items.
+///
+/// # Optional
+///
+/// Requires whichever feature backs `F` (e.g. `json`, `cbor`, `msgpack`, `postcard`).
+/// Without the `json` feature, `F` must be given explicitly since there is no default.
+#[cfg(feature = "json")]
+pub struct TypedWebSocket<T, F = crate::json::Json, S = WebSocket> {
+    inner: S,
+    _marker: PhantomData<fn() -> (T, F)>,
+}
+
+/// A [`WebSocket`] adapter that speaks `T` instead of raw [`Message`]s.
+///
+/// See the `json`-feature version of this type for details. Without `json` enabled
+/// there is no default format, so `F` must always be specified explicitly.
+#[cfg(not(feature = "json"))]
+pub struct TypedWebSocket<T, F, S = WebSocket> {
+    inner: S,
+    _marker: PhantomData<fn() -> (T, F)>,
+}
+
+impl<T, F, S> TypedWebSocket<T, F, S> {
+    /// Wraps `inner`, encoding outgoing items and decoding incoming ones using format `F`.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Unwraps this adapter, returning the underlying socket.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<T: Serialize, F: Format, S> Sink<T> for TypedWebSocket<T, F, S>
+where
+    S: Sink<Message, Error = Error> + Unpin,
+{
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.inner).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Error> {
+        let message = F::to_message(&item)?;
+        Pin::new(&mut self.inner).start_send(message)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+impl<T: DeserializeOwned, F: Format, S> Stream for TypedWebSocket<T, F, S>
+where
+    S: Stream<Item = Result<Message, Error>> + Unpin,
+{
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            return match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(message @ (Message::Text(_) | Message::Binary(_))))) => {
+                    Poll::Ready(Some(F::from_message(&message)))
+                }
+                // Control frames (Ping/Pong/Close) carry no payload of type `T`; skip them.
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(error))) => Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::Json;
+    use futures_util::{SinkExt, StreamExt};
+    use serde::Deserialize;
+    use std::collections::VecDeque;
+
+    #[derive(Debug, Default)]
+    struct MockSocket {
+        incoming: VecDeque<Result<Message, Error>>,
+        outgoing: Vec<Message>,
+    }
+
+    impl Stream for MockSocket {
+        type Item = Result<Message, Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.incoming.pop_front())
+        }
+    }
+
+    impl Sink<Message> for MockSocket {
+        type Error = Error;
+
+        fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: Message) -> Result<(), Error> {
+            self.outgoing.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        n: i32,
+    }
+
+    #[tokio::test]
+    async fn stream_skips_control_frames() {
+        let socket = MockSocket {
+            incoming: VecDeque::from([
+                Ok(Message::Ping(Vec::new())),
+                Ok(Message::Pong(Vec::new())),
+                Ok(Message::Close {
+                    code: 1000,
+                    reason: String::new(),
+                }),
+                Ok(Message::Text(r#"{"n":1}"#.to_string())),
+            ]),
+            outgoing: Vec::new(),
+        };
+        let mut typed: TypedWebSocket<Item, Json, MockSocket> = TypedWebSocket::new(socket);
+
+        let item = typed.next().await.unwrap().unwrap();
+        assert_eq!(item, Item { n: 1 });
+    }
+
+    #[tokio::test]
+    async fn stream_surfaces_decode_errors_without_ending() {
+        let socket = MockSocket {
+            incoming: VecDeque::from([
+                Ok(Message::Text("not json".to_string())),
+                Ok(Message::Text(r#"{"n":2}"#.to_string())),
+            ]),
+            outgoing: Vec::new(),
+        };
+        let mut typed: TypedWebSocket<Item, Json, MockSocket> = TypedWebSocket::new(socket);
+
+        let first = typed.next().await.unwrap();
+        assert!(first.is_err());
+        let second = typed.next().await.unwrap().unwrap();
+        assert_eq!(second, Item { n: 2 });
+    }
+
+    #[tokio::test]
+    async fn sink_encodes_item_via_format() {
+        let socket = MockSocket::default();
+        let mut typed: TypedWebSocket<Item, Json, MockSocket> = TypedWebSocket::new(socket);
+
+        typed.send(Item { n: 3 }).await.unwrap();
+
+        let socket = typed.into_inner();
+        assert_eq!(
+            socket.outgoing,
+            vec![Message::text_from_json(&Item { n: 3 }).unwrap()]
+        );
+    }
+}